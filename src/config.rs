@@ -0,0 +1,48 @@
+use bevy::log::warn;
+use serde::Deserialize;
+use std::fs;
+
+use crate::rule::Rule;
+
+const CONFIG_PATH: &str = "config.json5";
+
+/// Raw shape of `config.json5` as written on disk.
+#[derive(Deserialize)]
+struct RawConfig {
+    rule: String,
+}
+
+/// Settings loaded from [`CONFIG_PATH`] at startup.
+pub struct GameConfig {
+    pub rule: Rule,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self { rule: Rule::default() }
+    }
+}
+
+/// Loads [`GameConfig`] from `config.json5`, falling back to defaults if the
+/// file is missing or malformed.
+pub fn load_config() -> GameConfig {
+    let contents = match fs::read_to_string(CONFIG_PATH) {
+        Ok(contents) => contents,
+        Err(_) => return GameConfig::default(),
+    };
+
+    let raw: RawConfig = match json5::from_str(&contents) {
+        Ok(raw) => raw,
+        Err(err) => {
+            warn!("failed to parse {CONFIG_PATH}: {err}, falling back to defaults");
+            return GameConfig::default();
+        }
+    };
+
+    let rule = Rule::parse(&raw.rule).unwrap_or_else(|err| {
+        warn!("invalid rule `{}` in {CONFIG_PATH}: {err}, falling back to defaults", raw.rule);
+        Rule::default()
+    });
+
+    GameConfig { rule }
+}
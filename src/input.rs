@@ -0,0 +1,537 @@
+use std::fs;
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::log::{info, warn};
+use bevy::ecs::schedule::ParallelSystemDescriptorCoercion;
+use bevy::prelude::{
+    App, Component, CoreStage, EventReader, Input, KeyCode, MouseButton, OrthographicProjection, Plugin, Query, Res,
+    ResMut, Time, Transform, Vec2, With,
+};
+use bevy::utils::HashMap;
+use bevy::window::Windows;
+use serde::Deserialize;
+
+const LAYOUT_PATH: &str = "keybinds.json5";
+
+/// System label for [`update_cursor_position`], so other modules (e.g. the
+/// pattern stamp preview) can order their cursor-dependent systems after it
+/// within the same `PreUpdate` stage.
+pub const CURSOR_POSITION_LABEL: &str = "update_cursor_position";
+
+const PAN_SPEED: f32 = 300.0;
+const ZOOM_SPEED: f32 = 1.0;
+const MIN_ZOOM: f32 = 0.05;
+
+/// Marker for the camera entity that world-space systems pan/zoom.
+#[derive(Component)]
+pub struct Camera;
+
+/// The cursor's current position in world space, updated each frame.
+#[derive(Default)]
+pub struct CursorPosition(pub Option<Vec2>);
+
+pub struct CursorPlugin;
+
+impl Plugin for CursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CursorPosition::default())
+            .insert_resource(ActiveLayouts::load())
+            .insert_resource(ActionHandler::default())
+            .add_system_to_stage(CoreStage::PreUpdate, update_action_states)
+            .add_system_to_stage(CoreStage::PreUpdate, update_cursor_position.label(CURSOR_POSITION_LABEL))
+            .add_system(handle_pan_and_zoom)
+            .add_system(handle_layout_switch);
+    }
+}
+
+fn update_cursor_position(
+    windows: Res<Windows>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    mut cursor_position: ResMut<CursorPosition>,
+) {
+    let Some(window) = windows.get_primary() else {
+        return;
+    };
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+
+    cursor_position.0 = window.cursor_position().map(|screen_pos| {
+        let window_size = Vec2::new(window.width(), window.height());
+        let offset = screen_pos - window_size / 2.0;
+        (offset * projection.scale + camera_transform.translation.truncate()).floor()
+    });
+}
+
+/// Applies the accumulated [`Action::Pan`]/[`Action::Zoom`] axis values to
+/// the camera, replacing the separate keyboard/mouse pan-and-zoom systems
+/// now that both devices feed the same named actions.
+fn handle_pan_and_zoom(
+    action_handler: Res<ActionHandler>,
+    time: Res<Time>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera>>,
+) {
+    let Ok((mut transform, mut projection)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    let pan = action_handler.state(Action::Pan).value;
+    if pan != Vec2::ZERO {
+        let movement = pan.normalize_or_zero() * PAN_SPEED * projection.scale * time.delta_seconds();
+        transform.translation += movement.extend(0.0);
+    }
+
+    let zoom = action_handler.state(Action::Zoom).value.x;
+    if zoom != 0.0 {
+        projection.scale = (projection.scale * (1.0 - zoom * ZOOM_SPEED * time.delta_seconds())).max(MIN_ZOOM);
+    }
+}
+
+/// A named, device-agnostic input action, modeled on the lyra-engine action
+/// system: game code queries [`ActionHandler::state`] for one of these
+/// instead of matching on raw `KeyCode`/`MouseButton` values.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
+pub enum Action {
+    PlayPause,
+    StepForward,
+    DrawCell,
+    Pan,
+    Zoom,
+    StampToggle,
+    StampCycle,
+    StampRotate,
+    StampFlip,
+    StampLoad,
+    SwitchRule,
+    Save,
+    Load,
+}
+
+/// Whether an [`Action`] behaves as a discrete press or an accumulated
+/// analog value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionKind {
+    Button,
+    Axis,
+}
+
+impl Action {
+    pub fn kind(self) -> ActionKind {
+        match self {
+            Action::PlayPause
+            | Action::StepForward
+            | Action::DrawCell
+            | Action::StampToggle
+            | Action::StampCycle
+            | Action::StampRotate
+            | Action::StampFlip
+            | Action::StampLoad
+            | Action::SwitchRule
+            | Action::Save
+            | Action::Load => ActionKind::Button,
+            Action::Pan | Action::Zoom => ActionKind::Axis,
+        }
+    }
+}
+
+/// The resolved state of an [`Action`] for the current frame. Button actions
+/// use `pressed`/`just_pressed`; axis actions accumulate into `value`.
+#[derive(Clone, Copy, Default)]
+pub struct ActionState {
+    pub pressed: bool,
+    pub just_pressed: bool,
+    pub value: Vec2,
+}
+
+/// Per-frame resolved state for every [`Action`], populated from the active
+/// [`ActionLayout`] by [`update_action_states`].
+#[derive(Default)]
+pub struct ActionHandler {
+    states: HashMap<Action, ActionState>,
+}
+
+impl ActionHandler {
+    pub fn state(&self, action: Action) -> ActionState {
+        self.states.get(&action).copied().unwrap_or_default()
+    }
+}
+
+/// Which component of an [`ActionState::value`] a [`Binding::KeyAxis`]
+/// drives, since one axis action (e.g. `Pan`) is commonly bound from two
+/// separate key pairs.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AxisComponent {
+    X,
+    Y,
+}
+
+/// A physical input bound to an [`Action`].
+#[derive(Clone, Copy)]
+pub enum Binding {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    MouseScroll,
+    KeyAxis {
+        positive: KeyCode,
+        negative: KeyCode,
+        axis: AxisComponent,
+    },
+}
+
+impl Binding {
+    fn kind(&self) -> ActionKind {
+        match self {
+            Binding::Key(_) | Binding::MouseButton(_) => ActionKind::Button,
+            Binding::MouseScroll | Binding::KeyAxis { .. } => ActionKind::Axis,
+        }
+    }
+}
+
+/// One named set of action bindings. [`LAYOUT_PATH`] can declare several of
+/// these; [`ActiveLayouts`] holds all of them and tracks which is active.
+pub struct ActionLayout {
+    pub name: String,
+    pub bindings: Vec<(Action, Binding)>,
+}
+
+impl Default for ActionLayout {
+    fn default() -> Self {
+        Self {
+            name: "default".to_owned(),
+            bindings: vec![
+                (Action::PlayPause, Binding::Key(KeyCode::Space)),
+                (Action::StepForward, Binding::Key(KeyCode::Right)),
+                (Action::DrawCell, Binding::MouseButton(MouseButton::Left)),
+                (
+                    Action::Pan,
+                    Binding::KeyAxis {
+                        positive: KeyCode::D,
+                        negative: KeyCode::A,
+                        axis: AxisComponent::X,
+                    },
+                ),
+                (
+                    Action::Pan,
+                    Binding::KeyAxis {
+                        positive: KeyCode::W,
+                        negative: KeyCode::S,
+                        axis: AxisComponent::Y,
+                    },
+                ),
+                (Action::Zoom, Binding::MouseScroll),
+                (
+                    Action::Zoom,
+                    Binding::KeyAxis {
+                        positive: KeyCode::Equals,
+                        negative: KeyCode::Minus,
+                        axis: AxisComponent::X,
+                    },
+                ),
+                (Action::StampToggle, Binding::Key(KeyCode::Grave)),
+                (Action::StampCycle, Binding::Key(KeyCode::Key1)),
+                (Action::StampRotate, Binding::Key(KeyCode::R)),
+                (Action::StampFlip, Binding::Key(KeyCode::F)),
+                (Action::StampLoad, Binding::Key(KeyCode::F8)),
+                (Action::SwitchRule, Binding::Key(KeyCode::Tab)),
+                (Action::Save, Binding::Key(KeyCode::F5)),
+                (Action::Load, Binding::Key(KeyCode::F9)),
+            ],
+        }
+    }
+}
+
+impl ActionLayout {
+    /// Loads every binding layout declared in [`LAYOUT_PATH`], falling back
+    /// to a single [`ActionLayout::default`] if the file is missing,
+    /// malformed, or declares no layouts.
+    fn load_all() -> Vec<Self> {
+        let contents = match fs::read_to_string(LAYOUT_PATH) {
+            Ok(contents) => contents,
+            Err(_) => return vec![Self::default()],
+        };
+
+        let raw_layouts: Vec<RawLayout> = match json5::from_str(&contents) {
+            Ok(raw_layouts) => raw_layouts,
+            Err(err) => {
+                warn!("failed to parse {LAYOUT_PATH}: {err}, falling back to defaults");
+                return vec![Self::default()];
+            }
+        };
+
+        let layouts: Vec<Self> = raw_layouts.into_iter().map(RawLayout::into_layout).collect();
+        if layouts.is_empty() {
+            warn!("{LAYOUT_PATH} declared no layouts, falling back to defaults");
+            return vec![Self::default()];
+        }
+
+        layouts
+    }
+}
+
+/// Every loaded [`ActionLayout`] and which one is currently active, so
+/// players can cycle between remapped keybind sets at runtime (see
+/// [`handle_layout_switch`]) instead of being stuck with whichever layout
+/// loaded first.
+pub struct ActiveLayouts {
+    layouts: Vec<ActionLayout>,
+    index: usize,
+}
+
+impl ActiveLayouts {
+    pub fn load() -> Self {
+        Self {
+            layouts: ActionLayout::load_all(),
+            index: 0,
+        }
+    }
+
+    pub fn active(&self) -> &ActionLayout {
+        &self.layouts[self.index]
+    }
+
+    /// Switches to the next loaded layout, wrapping around.
+    pub fn cycle(&mut self) {
+        self.index = (self.index + 1) % self.layouts.len();
+    }
+}
+
+/// Cycles to the next loaded [`ActionLayout`] so players can swap keybind
+/// sets at runtime without restarting.
+fn handle_layout_switch(keyboard_input: Res<Input<KeyCode>>, mut layouts: ResMut<ActiveLayouts>) {
+    if keyboard_input.just_pressed(KeyCode::F6) {
+        layouts.cycle();
+        info!("switched to input layout {}", layouts.active().name);
+    }
+}
+
+#[derive(Deserialize)]
+struct RawLayout {
+    name: String,
+    bindings: Vec<RawBinding>,
+}
+
+impl RawLayout {
+    fn into_layout(self) -> ActionLayout {
+        let bindings = self
+            .bindings
+            .into_iter()
+            .filter_map(|raw_binding| match raw_binding.into_binding() {
+                Ok(binding) => Some(binding),
+                Err(err) => {
+                    warn!("skipping invalid binding in {LAYOUT_PATH}: {err}");
+                    None
+                }
+            })
+            .collect();
+
+        ActionLayout { name: self.name, bindings }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawBinding {
+    action: Action,
+    #[serde(flatten)]
+    input: RawInputSource,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum RawInputSource {
+    Key { key: String },
+    MouseButton { button: String },
+    MouseScroll,
+    KeyAxis {
+        positive: String,
+        negative: String,
+        axis: AxisComponent,
+    },
+}
+
+impl RawBinding {
+    fn into_binding(self) -> Result<(Action, Binding), String> {
+        let binding = match self.input {
+            RawInputSource::Key { key } => Binding::Key(key_from_name(&key)?),
+            RawInputSource::MouseButton { button } => Binding::MouseButton(mouse_button_from_name(&button)?),
+            RawInputSource::MouseScroll => Binding::MouseScroll,
+            RawInputSource::KeyAxis { positive, negative, axis } => Binding::KeyAxis {
+                positive: key_from_name(&positive)?,
+                negative: key_from_name(&negative)?,
+                axis,
+            },
+        };
+
+        let (action_kind, binding_kind) = (self.action.kind(), binding.kind());
+        if action_kind != binding_kind {
+            return Err(format!(
+                "{:?} is a {action_kind:?} action, can't bind it to a {binding_kind:?} input",
+                self.action
+            ));
+        }
+
+        Ok((self.action, binding))
+    }
+}
+
+fn key_from_name(name: &str) -> Result<KeyCode, String> {
+    if let Some(key) = single_letter_key(name) {
+        return Ok(key);
+    }
+
+    if let Some(key) = number_row_key(name) {
+        return Ok(key);
+    }
+
+    Ok(match name {
+        "Space" => KeyCode::Space,
+        "Tab" => KeyCode::Tab,
+        "Grave" => KeyCode::Grave,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Equals" => KeyCode::Equals,
+        "Minus" => KeyCode::Minus,
+        "F5" => KeyCode::F5,
+        "F8" => KeyCode::F8,
+        "F9" => KeyCode::F9,
+        _ => return Err(format!("unknown key name `{name}`")),
+    })
+}
+
+/// Matches number-row key names (`"Key0"`..`"Key9"`) to their `KeyCode`.
+fn number_row_key(name: &str) -> Option<KeyCode> {
+    Some(match name {
+        "Key0" => KeyCode::Key0,
+        "Key1" => KeyCode::Key1,
+        "Key2" => KeyCode::Key2,
+        "Key3" => KeyCode::Key3,
+        "Key4" => KeyCode::Key4,
+        "Key5" => KeyCode::Key5,
+        "Key6" => KeyCode::Key6,
+        "Key7" => KeyCode::Key7,
+        "Key8" => KeyCode::Key8,
+        "Key9" => KeyCode::Key9,
+        _ => return None,
+    })
+}
+
+/// Matches single-letter key names (`"A"`..`"Z"`) to their `KeyCode`.
+fn single_letter_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+
+    Some(match c.to_ascii_uppercase() {
+        'A' => KeyCode::A,
+        'B' => KeyCode::B,
+        'C' => KeyCode::C,
+        'D' => KeyCode::D,
+        'E' => KeyCode::E,
+        'F' => KeyCode::F,
+        'G' => KeyCode::G,
+        'H' => KeyCode::H,
+        'I' => KeyCode::I,
+        'J' => KeyCode::J,
+        'K' => KeyCode::K,
+        'L' => KeyCode::L,
+        'M' => KeyCode::M,
+        'N' => KeyCode::N,
+        'O' => KeyCode::O,
+        'P' => KeyCode::P,
+        'Q' => KeyCode::Q,
+        'R' => KeyCode::R,
+        'S' => KeyCode::S,
+        'T' => KeyCode::T,
+        'U' => KeyCode::U,
+        'V' => KeyCode::V,
+        'W' => KeyCode::W,
+        'X' => KeyCode::X,
+        'Y' => KeyCode::Y,
+        'Z' => KeyCode::Z,
+        _ => return None,
+    })
+}
+
+fn mouse_button_from_name(name: &str) -> Result<MouseButton, String> {
+    match name {
+        "Left" => Ok(MouseButton::Left),
+        "Right" => Ok(MouseButton::Right),
+        "Middle" => Ok(MouseButton::Middle),
+        _ => Err(format!("unknown mouse button name `{name}`")),
+    }
+}
+
+/// Resolves [`ActionHandler`]'s per-frame state from the active
+/// [`ActionLayout`] and the raw `bevy` input resources.
+fn update_action_states(
+    layouts: Res<ActiveLayouts>,
+    mut action_handler: ResMut<ActionHandler>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    mut scroll_events: EventReader<MouseWheel>,
+) {
+    let scroll = scroll_events
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y * 0.01,
+        })
+        .sum::<f32>();
+
+    let mut states = HashMap::<Action, ActionState>::default();
+    for (action, binding) in &layouts.active().bindings {
+        let state = states.entry(*action).or_default();
+
+        match binding {
+            Binding::Key(key) => {
+                state.pressed |= keyboard_input.pressed(*key);
+                state.just_pressed |= keyboard_input.just_pressed(*key);
+            }
+            Binding::MouseButton(button) => {
+                state.pressed |= mouse_button_input.pressed(*button);
+                state.just_pressed |= mouse_button_input.just_pressed(*button);
+            }
+            Binding::MouseScroll => state.value.x += scroll,
+            Binding::KeyAxis { positive, negative, axis } => {
+                let value = keyboard_input.pressed(*positive) as i32 as f32 - keyboard_input.pressed(*negative) as i32 as f32;
+                match axis {
+                    AxisComponent::X => state.value.x += value,
+                    AxisComponent::Y => state.value.y += value,
+                }
+            }
+        }
+    }
+
+    action_handler.states = states;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_binding(action: Action, input: RawInputSource) -> RawBinding {
+        RawBinding { action, input }
+    }
+
+    #[test]
+    fn into_binding_accepts_matching_kinds() {
+        let binding = raw_binding(Action::PlayPause, RawInputSource::Key { key: "Space".to_owned() });
+        assert!(binding.into_binding().is_ok());
+    }
+
+    #[test]
+    fn into_binding_rejects_mismatched_kinds() {
+        let binding = raw_binding(Action::PlayPause, RawInputSource::MouseScroll);
+        assert!(binding.into_binding().is_err());
+    }
+
+    #[test]
+    fn key_from_name_resolves_grave_and_number_row() {
+        assert_eq!(key_from_name("Grave").unwrap(), KeyCode::Grave);
+        assert_eq!(key_from_name("Key1").unwrap(), KeyCode::Key1);
+        assert!(key_from_name("Nonsense").is_err());
+    }
+}
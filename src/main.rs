@@ -6,13 +6,18 @@
     unused_lifetimes
 )]
 
+mod config;
 mod input;
+mod rle;
+mod rule;
+mod stamp;
 mod utils;
 
-use bevy::log::{Level, LogSettings};
+use bevy::ecs::schedule::ParallelSystemDescriptorCoercion;
+use bevy::log::{info, warn, Level, LogSettings};
 use bevy::prelude::{
-    App, Assets, Camera2dBundle, Color, Commands, Component, ComputedVisibility, Entity, GlobalTransform, Handle,
-    Input, KeyCode, Mesh, MouseButton, Query, Res, ResMut, SystemSet, Transform, Vec2, Visibility,
+    App, Assets, Camera2dBundle, Color, Commands, Component, ComputedVisibility, CoreStage, Entity, GlobalTransform,
+    Handle, Mesh, Query, Res, ResMut, SystemSet, Transform, Vec2, Visibility,
 };
 use bevy::render::mesh::Indices;
 use bevy::render::render_resource::PrimitiveTopology;
@@ -24,11 +29,26 @@ use bevy::DefaultPlugins;
 use bevy_inspector_egui::Inspectable;
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::{RegisterInspectable, WorldInspectorPlugin};
-use input::{CursorPanState, CursorPlugin, CursorPosition};
+use config::load_config;
+use input::{Action, ActionHandler, CursorPlugin, CursorPosition};
+use rle::RleParseError;
+use rule::Rule;
+use stamp::{
+    handle_stamp_draw, handle_stamp_input, handle_stamp_load, render_stamp_preview, resolve_stamp_preview,
+    StampPreview, StampState,
+};
 use utils::{from_chunk_pos, to_chunk_pos};
 
 use crate::input::Camera;
 
+/// Known rulestring presets cycled through by [`handle_rule_switch`].
+const RULE_PRESETS: &[(&str, &str)] = &[
+    ("Conway", "B3/S23"),
+    ("HighLife", "B36/S23"),
+    ("Day & Night", "B3678/S34678"),
+    ("Frogs (Generations)", "B34/S12/3"),
+];
+
 fn main() {
     #[cfg(target_arch = "wasm32")]
     {
@@ -36,6 +56,7 @@ fn main() {
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
     }
 
+    let config = load_config();
     let mut app = App::new();
 
     // Constructing our app
@@ -49,16 +70,21 @@ fn main() {
         level: Level::INFO,
         ..Default::default()
     })
-    .insert_resource(CursorPanState::default())
-    .insert_resource(GlobalState::default())
+    .insert_resource(GlobalState::new(config.rule))
     .insert_resource(CursorDrawState::default())
+    .insert_resource(StampState::default())
     .add_plugins(DefaultPlugins)
     .add_plugin(CursorPlugin)
     .add_startup_system(init_world)
-    .add_system(input::handle_keyboard_pan_and_zoom)
-    .add_system(input::handle_mouse_pan_and_zoom)
+    .add_system_to_stage(CoreStage::PreUpdate, resolve_stamp_preview.after(input::CURSOR_POSITION_LABEL))
     .add_system(handle_click)
     .add_system(handle_play_pause)
+    .add_system(handle_rule_switch)
+    .add_system(handle_save_load)
+    .add_system(handle_stamp_input)
+    .add_system(handle_stamp_load)
+    .add_system(handle_stamp_draw)
+    .add_system(render_stamp_preview)
     .add_system(tick_universe)
     .add_system_set(
         SystemSet::new()
@@ -83,20 +109,97 @@ fn main() {
     app.run();
 }
 
-fn init_world(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
+fn init_world(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
     commands.spawn_bundle(Camera2dBundle::default()).insert(Camera);
     commands
         .spawn()
-        .insert(Universe::new(materials.add(ColorMaterial::from(Color::GREEN))));
+        // White so the per-vertex ATTRIBUTE_COLOR tint from recalculate_mesh
+        // isn't multiplied against a flat material color.
+        .insert(Universe::new(materials.add(ColorMaterial::from(Color::WHITE))));
+
+    // Dedicated overlay entity the stamp tool's preview is rendered into,
+    // kept separate from chunk meshes so it never touches `current_gen`.
+    commands
+        .spawn()
+        .insert(Mesh2dHandle(meshes.add(Mesh::new(PrimitiveTopology::TriangleList))))
+        .insert(materials.add(ColorMaterial::from(Color::rgba(1.0, 1.0, 1.0, 0.35))))
+        .insert(Transform::from_xyz(0.0, 0.0, 1.0))
+        .insert(GlobalTransform::default())
+        .insert(Visibility::default())
+        .insert(ComputedVisibility::default())
+        .insert(StampPreview);
 }
 
 pub struct GlobalState {
     pub paused: bool,
+    pub rule: Rule,
+    rule_preset_index: usize,
+}
+
+impl GlobalState {
+    fn new(rule: Rule) -> Self {
+        Self {
+            paused: true,
+            rule,
+            rule_preset_index: 0,
+        }
+    }
 }
 
 impl Default for GlobalState {
     fn default() -> Self {
-        Self { paused: true }
+        Self::new(Rule::default())
+    }
+}
+
+/// Cycles through [`RULE_PRESETS`] on [`Action::SwitchRule`] so the active
+/// rule can be switched at runtime without editing the config file.
+fn handle_rule_switch(action_handler: Res<ActionHandler>, mut state: ResMut<GlobalState>) {
+    if action_handler.state(Action::SwitchRule).just_pressed {
+        state.rule_preset_index = (state.rule_preset_index + 1) % RULE_PRESETS.len();
+        let (name, rulestring) = RULE_PRESETS[state.rule_preset_index];
+        state.rule = Rule::parse(rulestring).expect("rule presets are always valid rulestrings");
+        info!("switched to rule {name} ({rulestring})");
+    }
+}
+
+/// Saves/loads the universe as an RLE pattern via a native file picker, on
+/// [`Action::Save`]/[`Action::Load`].
+fn handle_save_load(
+    action_handler: Res<ActionHandler>,
+    mut universe: Query<&mut Universe>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut state: ResMut<GlobalState>,
+) {
+    let Ok(mut universe) = universe.get_single_mut() else {
+        return;
+    };
+
+    if action_handler.state(Action::Save).just_pressed {
+        if let Some(path) = rfd::FileDialog::new().add_filter("RLE pattern", &["rle"]).save_file() {
+            let rle = universe.save_rle(&state.rule);
+            if let Err(err) = std::fs::write(&path, rle) {
+                warn!("failed to save pattern to {}: {err}", path.display());
+            }
+        }
+    }
+
+    if action_handler.state(Action::Load).just_pressed {
+        if let Some(path) = rfd::FileDialog::new().add_filter("RLE pattern", &["rle"]).pick_file() {
+            match std::fs::read_to_string(&path) {
+                Ok(contents) => match universe.load_rle(&mut commands, &mut meshes, &contents, (0, 0)) {
+                    Ok(Some(rule)) => state.rule = rule,
+                    Ok(None) => {}
+                    Err(err) => warn!("failed to parse {}: {err}", path.display()),
+                },
+                Err(err) => warn!("failed to read {}: {err}", path.display()),
+            }
+        }
     }
 }
 
@@ -175,7 +278,8 @@ impl Universe {
             let local_x = ((50.0 + (x % 50.0)) % 50.0) as usize;
             let local_y = ((50.0 + (y % 50.0)) % 50.0) as usize;
 
-            chunk.current_gen[local_x][local_y] = state;
+            chunk.current_gen[local_x][local_y] = state as u8;
+            chunk.age[local_x][local_y] = 0;
         }
     }
 
@@ -187,7 +291,7 @@ impl Universe {
             let local_x = ((50.0 + (x % 50.0)) % 50.0) as usize;
             let local_y = ((50.0 + (y % 50.0)) % 50.0) as usize;
 
-            return chunk.current_gen[local_x][local_y];
+            return chunk.current_gen[local_x][local_y] == 1;
         }
 
         false
@@ -199,7 +303,44 @@ impl Universe {
         self.set_cell_state(commands, meshes, world_pos, !current_state);
     }
 
-    fn tick(&mut self, commands: &mut Commands, meshes: &mut ResMut<Assets<Mesh>>) {
+    /// Loads an RLE pattern, placing its cells relative to `origin`. Returns
+    /// the rule embedded in the pattern's header, if any.
+    fn load_rle(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        contents: &str,
+        origin: (i32, i32),
+    ) -> Result<Option<Rule>, RleParseError> {
+        let pattern = rle::parse(contents)?;
+
+        for (x, y) in pattern.cells {
+            let world_pos = ((origin.0 + x) as f32, (origin.1 + y) as f32);
+            self.set_cell_state(commands, meshes, world_pos, true);
+        }
+
+        Ok(pattern.rule)
+    }
+
+    /// Exports every alive cell across all chunks as an RLE pattern string.
+    fn save_rle(&self, rule: &Rule) -> String {
+        let cells = self
+            .chunks
+            .iter()
+            .flat_map(|(chunk_pos, chunk)| {
+                let (chunk_world_x, chunk_world_y) = from_chunk_pos(*chunk_pos);
+                chunk.current_gen.iter().enumerate().flat_map(move |(local_x, column)| {
+                    column.iter().enumerate().filter_map(move |(local_y, state)| {
+                        (*state == 1).then_some((chunk_world_x + local_x as i32, chunk_world_y + local_y as i32))
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        rle::format(&cells, rule)
+    }
+
+    fn tick(&mut self, commands: &mut Commands, meshes: &mut ResMut<Assets<Mesh>>, rule: &Rule) {
         // Prepare every chunk for being ticked
         for (_, chunk) in &mut self.chunks {
             chunk.prepare_tick()
@@ -232,16 +373,19 @@ impl Universe {
             .collect::<HashMap<_, _>>();
 
         for ((x, y), chunk) in &mut self.chunks {
-            chunk.tick([
-                *chunk_data.get(&(x - 1, *y)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(x - 1, y + 1)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(*x, y + 1)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(x + 1, y + 1)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(x + 1, *y)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(x + 1, y - 1)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(*x, y - 1)).unwrap_or(&[[false; 50]; 50]),
-                *chunk_data.get(&(x - 1, y - 1)).unwrap_or(&[[false; 50]; 50]),
-            ]);
+            chunk.tick(
+                [
+                    *chunk_data.get(&(x - 1, *y)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(x - 1, y + 1)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(*x, y + 1)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(x + 1, y + 1)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(x + 1, *y)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(x + 1, y - 1)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(*x, y - 1)).unwrap_or(&[[0; 50]; 50]),
+                    *chunk_data.get(&(x - 1, y - 1)).unwrap_or(&[[0; 50]; 50]),
+                ],
+                rule,
+            );
         }
 
         for (_, chunk) in &mut self.chunks {
@@ -260,7 +404,7 @@ fn tick_universe(
 ) {
     if let Ok(mut universe) = universe.get_single_mut() {
         if !state.paused {
-            universe.tick(&mut commands, &mut meshes);
+            universe.tick(&mut commands, &mut meshes, &state.rule);
         } else {
             for (_, chunk) in &mut universe.chunks {
                 chunk.recalculate_mesh(&mut meshes);
@@ -275,25 +419,30 @@ pub struct CursorDrawState {
 }
 
 fn handle_click(
-    mouse_btn_input: Res<Input<MouseButton>>,
+    action_handler: Res<ActionHandler>,
     cursor_pos: Res<CursorPosition>,
     mut universe: Query<&mut Universe>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut draw_state: ResMut<CursorDrawState>,
     state: Res<GlobalState>,
+    stamp: Res<StampState>,
 ) {
-    if !state.paused {
+    // While the stamp tool is enabled, DrawCell commits a pattern via
+    // handle_stamp_draw instead of toggling a single cell here.
+    if !state.paused || stamp.enabled {
         return;
     }
 
+    let draw_cell = action_handler.state(Action::DrawCell);
+
     if let Ok(mut universe) = universe.get_single_mut() {
         if let Some(Vec2 { x, y }) = cursor_pos.0 {
-            if mouse_btn_input.just_pressed(MouseButton::Left) {
+            if draw_cell.just_pressed {
                 draw_state.cell_state = !universe.get_cell_state((x, y));
             }
 
-            if mouse_btn_input.pressed(MouseButton::Left) {
+            if draw_cell.pressed {
                 universe.set_cell_state(&mut commands, &mut meshes, (x, y), draw_state.cell_state);
             }
         }
@@ -301,19 +450,19 @@ fn handle_click(
 }
 
 fn handle_play_pause(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_handler: Res<ActionHandler>,
     mut state: ResMut<GlobalState>,
     mut universe: Query<&mut Universe>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Space) {
+    if action_handler.state(Action::PlayPause).just_pressed {
         state.paused = !state.paused
     }
 
-    if keyboard_input.just_pressed(KeyCode::Right) {
+    if action_handler.state(Action::StepForward).just_pressed {
         if let Ok(mut universe) = universe.get_single_mut() {
-            universe.tick(&mut commands, &mut meshes);
+            universe.tick(&mut commands, &mut meshes, &state.rule);
         }
     }
 }
@@ -323,13 +472,23 @@ struct Chunk {
     pos: (i32, i32),
 
     // Previous generation stuff
-    last_gen: [[bool; 50]; 50],
+    last_gen: [[u8; 50]; 50],
     last_gen_alive: i32,
 
     // Current generation stuff
-    current_gen: [[bool; 50]; 50],
+    current_gen: [[u8; 50]; 50],
     current_gen_alive: i32,
 
+    // How many ticks each cell has spent alive or decaying, for the
+    // gradient tint in recalculate_mesh. Reset to 0 on birth.
+    age: [[u8; 50]; 50],
+
+    // The active rule's total state count as of the last tick, so
+    // recalculate_mesh can place a decaying cell's color within its rule's
+    // decay phases rather than reusing its pre-decay age. Defaults to 2
+    // (no decay states) until this chunk is first ticked.
+    rule_states: u8,
+
     // Bevy things
     mesh: Handle<Mesh>,
     entity: Entity,
@@ -341,10 +500,12 @@ impl Chunk {
     fn new(pos: (i32, i32), mesh: Handle<Mesh>, entity: Entity) -> Self {
         Self {
             pos,
-            last_gen: [[false; 50]; 50],
+            last_gen: [[0; 50]; 50],
             last_gen_alive: 0,
-            current_gen: [[false; 50]; 50],
+            current_gen: [[0; 50]; 50],
             current_gen_alive: 0,
+            age: [[0; 50]; 50],
+            rule_states: 2,
             mesh,
             entity,
         }
@@ -356,21 +517,21 @@ impl Chunk {
         self.last_gen = self.current_gen;
         self.last_gen_alive = self.current_gen_alive;
 
-        self.current_gen = [[false; 50]; 50];
+        self.current_gen = [[0; 50]; 50];
         self.current_gen_alive = 0;
     }
 
-    // 0: chunk_data.get(&(x - 1, y    )).unwrap_or(&[[false; 50]; 50]),
-    // 1: chunk_data.get(&(x - 1, y + 1)).unwrap_or(&[[false; 50]; 50]),
-    // 2: chunk_data.get(&(x    , y + 1)).unwrap_or(&[[false; 50]; 50]),
-    // 3: chunk_data.get(&(x + 1, y + 1)).unwrap_or(&[[false; 50]; 50]),
-    // 4: chunk_data.get(&(x + 1, y    )).unwrap_or(&[[false; 50]; 50]),
-    // 5: chunk_data.get(&(x + 1, y - 1)).unwrap_or(&[[false; 50]; 50]),
-    // 6: chunk_data.get(&(x    , y - 1)).unwrap_or(&[[false; 50]; 50]),
-    // 7: chunk_data.get(&(x - 1, y - 1)).unwrap_or(&[[false; 50]; 50]),
+    // 0: chunk_data.get(&(x - 1, y    )).unwrap_or(&[[0; 50]; 50]),
+    // 1: chunk_data.get(&(x - 1, y + 1)).unwrap_or(&[[0; 50]; 50]),
+    // 2: chunk_data.get(&(x    , y + 1)).unwrap_or(&[[0; 50]; 50]),
+    // 3: chunk_data.get(&(x + 1, y + 1)).unwrap_or(&[[0; 50]; 50]),
+    // 4: chunk_data.get(&(x + 1, y    )).unwrap_or(&[[0; 50]; 50]),
+    // 5: chunk_data.get(&(x + 1, y - 1)).unwrap_or(&[[0; 50]; 50]),
+    // 6: chunk_data.get(&(x    , y - 1)).unwrap_or(&[[0; 50]; 50]),
+    // 7: chunk_data.get(&(x - 1, y - 1)).unwrap_or(&[[0; 50]; 50]),
 
     #[rustfmt::skip]
-    fn get_neighbor_status(&self, chunk_neighbors_state: &[[[bool; 50]; 50]; 8], pos: (i32, i32)) -> bool {
+    fn get_neighbor_status(&self, chunk_neighbors_state: &[[[u8; 50]; 50]; 8], pos: (i32, i32)) -> u8 {
         match pos {
             (-1, 50) => chunk_neighbors_state[1][49][0],
             (50, 50) => chunk_neighbors_state[3][0][0],
@@ -384,39 +545,57 @@ impl Chunk {
         }
     }
 
+    /// Counts neighbors in state `1`. Decaying (refractory) Generations
+    /// states don't count as alive for birth/survival purposes.
     #[rustfmt::skip]
-    fn get_alive_neighbors(&self, chunk_neighbors_state: &[[[bool; 50]; 50]; 8], (x, y): (i32, i32)) -> i32 {
+    fn get_alive_neighbors(&self, chunk_neighbors_state: &[[[u8; 50]; 50]; 8], (x, y): (i32, i32)) -> i32 {
         let mut total_alive = 0;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x - 1, y    )) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x - 1, y + 1)) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x    , y + 1)) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x + 1, y + 1)) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x + 1, y    )) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x + 1, y - 1)) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x    , y - 1)) as i32;
-        total_alive += self.get_neighbor_status(chunk_neighbors_state, (x - 1, y - 1)) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x - 1, y    )) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x - 1, y + 1)) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x    , y + 1)) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x + 1, y + 1)) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x + 1, y    )) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x + 1, y - 1)) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x    , y - 1)) == 1) as i32;
+        total_alive += (self.get_neighbor_status(chunk_neighbors_state, (x - 1, y - 1)) == 1) as i32;
         total_alive
     }
 
     fn tick(
         &mut self,
-        chunk_neighbors_state: [[[bool; 50]; 50]; 8],
+        chunk_neighbors_state: [[[u8; 50]; 50]; 8],
         // Clockwise starting west - universe: &mut Universe,
+        rule: &Rule,
     ) {
+        self.rule_states = rule.states;
+
         for x in 0..50 {
             for y in 0..50 {
-                let alive = self.last_gen[x as usize][y as usize];
-                let alive_neighbors = self.get_alive_neighbors(&chunk_neighbors_state, (x, y));
+                let (x, y) = (x as usize, y as usize);
+                let state = self.last_gen[x][y];
+                let alive_neighbors = self.get_alive_neighbors(&chunk_neighbors_state, (x as i32, y as i32));
+
+                let now_state = match state {
+                    0 if rule.birth[alive_neighbors as usize] => 1,
+                    0 => 0,
+                    1 if rule.survive[alive_neighbors as usize] => 1,
+                    1 if rule.is_generations() => 2,
+                    1 => 0,
+                    decaying if decaying + 1 < rule.states => decaying + 1,
+                    _ => 0,
+                };
 
-                let now_alive = match alive_neighbors {
-                    2 | 3 if alive => true,
-                    3 if !alive => true,
-                    _ => false,
+                self.age[x][y] = if now_state == 0 {
+                    0
+                } else if state == 0 {
+                    0
+                } else {
+                    self.age[x][y].saturating_add(1)
                 };
 
-                self.current_gen[x as usize][y as usize] = now_alive;
+                self.current_gen[x][y] = now_state;
 
-                if now_alive {
+                if now_state == 1 {
                     self.current_gen_alive += 1;
                 }
             }
@@ -424,25 +603,27 @@ impl Chunk {
     }
 
     fn recalculate_mesh(&mut self, meshes: &mut ResMut<Assets<Mesh>>) {
-        let mut verticies = Vec::<([f32; 3], [f32; 3], [f32; 2])>::with_capacity(50 * 50 * 4);
+        let mut verticies = Vec::<([f32; 3], [f32; 3], [f32; 2], [f32; 4])>::with_capacity(50 * 50 * 4);
         let mut indicies = Vec::<u32>::with_capacity(50 * 50 * 6);
 
         let mut index = 0;
         for x in 0..50 {
             for y in 0..50 {
-                let alive = self.current_gen[x][y];
+                let state = self.current_gen[x][y];
+
+                if state != 0 {
+                    let color = cell_color(state, self.age[x][y], self.rule_states);
 
-                if alive {
                     // Adding the veriticies
                     let y0 = y as f32;
                     let y1 = y as f32 + 1.0;
                     let x0 = x as f32;
                     let x1 = x as f32 + 1.0;
 
-                    verticies.push(([x0, y0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0]));
-                    verticies.push(([x0, y1, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0]));
-                    verticies.push(([x1, y1, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0]));
-                    verticies.push(([x1, y0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0]));
+                    verticies.push(([x0, y0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0], color));
+                    verticies.push(([x0, y1, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0], color));
+                    verticies.push(([x1, y1, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0], color));
+                    verticies.push(([x1, y0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0], color));
 
                     // Adding the indicies
                     indicies.push(4 * index as u32);
@@ -458,15 +639,17 @@ impl Chunk {
             }
         }
 
-        let verticies_positions = verticies.iter().map(|(p, _, _)| *p).collect::<Vec<_>>();
-        let verticies_normals = verticies.iter().map(|(_, n, _)| *n).collect::<Vec<_>>();
-        let verticies_uv = verticies.iter().map(|(_, _, u)| *u).collect::<Vec<_>>();
+        let verticies_positions = verticies.iter().map(|(p, _, _, _)| *p).collect::<Vec<_>>();
+        let verticies_normals = verticies.iter().map(|(_, n, _, _)| *n).collect::<Vec<_>>();
+        let verticies_uv = verticies.iter().map(|(_, _, u, _)| *u).collect::<Vec<_>>();
+        let verticies_color = verticies.iter().map(|(_, _, _, c)| *c).collect::<Vec<_>>();
 
         if let Some(mesh) = meshes.get_mut(&self.mesh) {
             mesh.set_indices(Some(Indices::U32(indicies)));
             mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, verticies_positions);
             mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, verticies_normals);
             mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, verticies_uv);
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, verticies_color);
         }
     }
 
@@ -474,3 +657,25 @@ impl Chunk {
         self.current_gen != self.last_gen
     }
 }
+
+/// Maps a cell's state and age to a per-vertex tint: state `1` cells fade
+/// from bright to a darker green the longer they stay alive, while
+/// Generations' decaying states (`2..states`) fade from amber toward black
+/// as they step through their refractory phases.
+fn cell_color(state: u8, age: u8, states: u8) -> [f32; 4] {
+    const MAX_AGE: f32 = 32.0;
+
+    if state == 1 {
+        let t = (age as f32 / MAX_AGE).min(1.0);
+        [0.2 + 0.6 * (1.0 - t), 1.0 - 0.4 * t, 0.2 + 0.2 * (1.0 - t), 1.0]
+    } else {
+        // `age` keeps accumulating across a cell's whole lifetime (alive +
+        // decaying), so it saturates long before a long-lived cell starts
+        // decaying and every decay phase would render identically. Derive
+        // `t` from how far `state` sits within this rule's decay phases
+        // (`2..states`) instead.
+        let decay_phases = states.saturating_sub(3).max(1) as f32;
+        let t = ((state - 2) as f32 / decay_phases).min(1.0);
+        [0.9 - 0.5 * t, 0.5 - 0.4 * t, 0.1, 1.0]
+    }
+}
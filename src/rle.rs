@@ -0,0 +1,233 @@
+use std::fmt::Write as _;
+
+use crate::rule::Rule;
+
+/// Largest run-count accepted in the body token stream. Bounds a malformed
+/// or adversarial file (e.g. a digit run like `2000000000o`) to a sane
+/// allocation instead of overflowing the accumulator or driving the live
+/// cell loop into an effectively unbounded spin.
+const MAX_RUN_COUNT: i32 = 1_000_000;
+
+/// A parsed RLE pattern: its optional embedded rule and the list of live
+/// cells as `(x, y)` offsets from the top-left corner. The header's
+/// declared `x`/`y` bounding box is validated but not kept — nothing in
+/// the crate places a pattern by anything other than its actual cells.
+pub struct RlePattern {
+    pub rule: Option<Rule>,
+    pub cells: Vec<(i32, i32)>,
+}
+
+/// Parses the standard Run-Length Encoded Game of Life format: a header
+/// line (`x = m, y = n, rule = B3/S23`) followed by a body token stream
+/// where an optional run-count precedes a tag (`b` dead, `o` alive, `$` end
+/// of row, `!` end of pattern).
+pub fn parse(input: &str) -> Result<RlePattern, RleParseError> {
+    let mut header = None;
+    let mut cells = Vec::new();
+    let mut run_count: Option<i32> = None;
+    let mut x = 0;
+    let mut y = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if header.is_none() {
+            header = Some(parse_header(line)?);
+            continue;
+        }
+
+        for c in line.chars() {
+            match c {
+                '0'..='9' => {
+                    let digit = c.to_digit(10).unwrap() as i32;
+                    run_count = Some(
+                        run_count
+                            .unwrap_or(0)
+                            .checked_mul(10)
+                            .and_then(|n| n.checked_add(digit))
+                            .filter(|&n| n <= MAX_RUN_COUNT)
+                            .ok_or(RleParseError::RunCountTooLarge)?,
+                    );
+                }
+                'b' => x += run_count.take().unwrap_or(1),
+                'o' => {
+                    for _ in 0..run_count.take().unwrap_or(1) {
+                        cells.push((x, y));
+                        x += 1;
+                    }
+                }
+                '$' => {
+                    y += run_count.take().unwrap_or(1);
+                    x = 0;
+                }
+                '!' => {
+                    let rule = header.ok_or(RleParseError::MissingHeader)?;
+                    return Ok(RlePattern { rule, cells });
+                }
+                _ if c.is_whitespace() => {}
+                _ => return Err(RleParseError::UnexpectedToken(c)),
+            }
+        }
+    }
+
+    Err(RleParseError::MissingTerminator)
+}
+
+fn parse_header(line: &str) -> Result<Option<Rule>, RleParseError> {
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+
+    for field in line.split(',') {
+        let (key, value) = field.split_once('=').ok_or(RleParseError::MalformedHeader)?;
+        match key.trim() {
+            "x" => width = Some(value.trim().parse::<i32>().map_err(|_| RleParseError::MalformedHeader)?),
+            "y" => height = Some(value.trim().parse::<i32>().map_err(|_| RleParseError::MalformedHeader)?),
+            "rule" => rule = Some(Rule::parse(value.trim()).map_err(RleParseError::InvalidRule)?),
+            _ => {}
+        }
+    }
+
+    width.ok_or(RleParseError::MalformedHeader)?;
+    height.ok_or(RleParseError::MalformedHeader)?;
+
+    Ok(rule)
+}
+
+/// Formats a set of live cells (in world coordinates) as RLE, tight-cropped
+/// to their bounding box.
+pub fn format(cells: &[(i32, i32)], rule: &Rule) -> String {
+    if cells.is_empty() {
+        return format!("x = 0, y = 0, rule = {}\n!\n", format_rulestring(rule));
+    }
+
+    let min_x = cells.iter().map(|(x, _)| *x).min().unwrap();
+    let max_x = cells.iter().map(|(x, _)| *x).max().unwrap();
+    let min_y = cells.iter().map(|(_, y)| *y).min().unwrap();
+    let max_y = cells.iter().map(|(_, y)| *y).max().unwrap();
+
+    let width = max_x - min_x + 1;
+    let height = max_y - min_y + 1;
+
+    let mut alive = vec![vec![false; width as usize]; height as usize];
+    for (x, y) in cells {
+        alive[(y - min_y) as usize][(x - min_x) as usize] = true;
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(out, "x = {width}, y = {height}, rule = {}", format_rulestring(rule));
+
+    for (row_idx, row) in alive.iter().enumerate() {
+        let mut run_tag = None;
+        let mut run_len = 0;
+
+        for &cell in row {
+            let tag = if cell { 'o' } else { 'b' };
+            match run_tag {
+                Some(t) if t == tag => run_len += 1,
+                Some(t) => {
+                    write_run(&mut out, run_len, t);
+                    run_tag = Some(tag);
+                    run_len = 1;
+                }
+                None => {
+                    run_tag = Some(tag);
+                    run_len = 1;
+                }
+            }
+        }
+
+        // Trailing dead runs never need to be emitted.
+        if run_tag == Some('o') {
+            write_run(&mut out, run_len, 'o');
+        }
+
+        out.push(if row_idx + 1 < alive.len() { '$' } else { '!' });
+    }
+
+    out.push('\n');
+    out
+}
+
+fn write_run(out: &mut String, count: usize, tag: char) {
+    if count > 1 {
+        let _ = write!(out, "{count}");
+    }
+    out.push(tag);
+}
+
+fn format_rulestring(rule: &Rule) -> String {
+    let mut out = String::from("B");
+    for (n, _) in rule.birth.iter().enumerate().filter(|(_, alive)| **alive) {
+        let _ = write!(out, "{n}");
+    }
+    out.push_str("/S");
+    for (n, _) in rule.survive.iter().enumerate().filter(|(_, alive)| **alive) {
+        let _ = write!(out, "{n}");
+    }
+    if rule.is_generations() {
+        let _ = write!(out, "/{}", rule.states);
+    }
+    out
+}
+
+#[derive(Debug)]
+pub enum RleParseError {
+    MalformedHeader,
+    MissingHeader,
+    MissingTerminator,
+    UnexpectedToken(char),
+    InvalidRule(crate::rule::RuleParseError),
+    RunCountTooLarge,
+}
+
+impl std::fmt::Display for RleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RleParseError::MalformedHeader => write!(f, "malformed RLE header line"),
+            RleParseError::MissingHeader => write!(f, "RLE body appeared before a header line"),
+            RleParseError::MissingTerminator => write!(f, "RLE pattern is missing its `!` terminator"),
+            RleParseError::UnexpectedToken(c) => write!(f, "unexpected RLE token `{c}`"),
+            RleParseError::InvalidRule(err) => write!(f, "invalid rule in RLE header: {err}"),
+            RleParseError::RunCountTooLarge => write!(f, "RLE run-count exceeds {MAX_RUN_COUNT}"),
+        }
+    }
+}
+
+impl std::error::Error for RleParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_glider() {
+        let pattern = parse("x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n").unwrap();
+        assert_eq!(pattern.cells, vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        assert_eq!(pattern.rule, Some(Rule::parse("B3/S23").unwrap()));
+    }
+
+    #[test]
+    fn rejects_run_count_over_the_limit() {
+        let input = format!("x = 1, y = 1, rule = B3/S23\n{}o!\n", MAX_RUN_COUNT as i64 + 1);
+        assert!(matches!(parse(&input), Err(RleParseError::RunCountTooLarge)));
+    }
+
+    #[test]
+    fn format_roundtrips_through_parse() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        let cells = vec![(0, 0), (1, 0), (1, 1)];
+        let rle = format(&cells, &rule);
+
+        let mut parsed = parse(&rle).unwrap().cells;
+        parsed.sort_unstable();
+
+        let mut expected = cells;
+        expected.sort_unstable();
+
+        assert_eq!(parsed, expected);
+    }
+}
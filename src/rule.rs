@@ -0,0 +1,136 @@
+use std::fmt;
+
+/// Birth/survival rule for a life-like cellular automaton, e.g. `B3/S23`
+/// (Conway's standard rules), `B36/S23` (HighLife), or a "Generations" rule
+/// like `B2/S23/3` whose trailing `/3` gives the total cell state count.
+///
+/// `birth[n]`/`survive[n]` is `true` when a dead/state-1 cell with `n`
+/// state-1 neighbors becomes/stays alive on the next tick. Only
+/// [`Rule::states`] is `> 2` for Generations rules; a cell that survives
+/// stays at state `1`, one that doesn't decays `2, 3, .., states - 1` before
+/// returning to `0` (dead). Decaying cells are drawn but don't count as
+/// live neighbors and can't be reborn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: [bool; 9],
+    pub survive: [bool; 9],
+    pub states: u8,
+}
+
+impl Rule {
+    /// Parses a rulestring of the form `B<digits>/S<digits>` (the order of
+    /// the `B`/`S` halves doesn't matter), with an optional trailing
+    /// `/<states>` Generations state count.
+    pub fn parse(rulestring: &str) -> Result<Self, RuleParseError> {
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+        let mut states = 2;
+        let mut seen_b = false;
+        let mut seen_s = false;
+
+        for half in rulestring.trim().split('/') {
+            let mut chars = half.chars();
+            match chars.next() {
+                Some('B') | Some('b') => {
+                    seen_b = true;
+                    parse_digits(chars, &mut birth)?;
+                }
+                Some('S') | Some('s') => {
+                    seen_s = true;
+                    parse_digits(chars, &mut survive)?;
+                }
+                Some(c) if c.is_ascii_digit() => {
+                    states = half.parse().map_err(|_| RuleParseError::InvalidStateCount(half.to_owned()))?;
+                }
+                _ => return Err(RuleParseError::MissingTag(half.to_owned())),
+            }
+        }
+
+        if !seen_b || !seen_s {
+            return Err(RuleParseError::MissingHalf);
+        }
+        if states < 2 {
+            return Err(RuleParseError::InvalidStateCount(states.to_string()));
+        }
+
+        Ok(Self { birth, survive, states })
+    }
+
+    /// Whether this is a Generations rule with refractory decay states
+    /// beyond plain dead/alive.
+    pub fn is_generations(&self) -> bool {
+        self.states > 2
+    }
+}
+
+fn parse_digits(chars: impl Iterator<Item = char>, table: &mut [bool; 9]) -> Result<(), RuleParseError> {
+    for digit in chars {
+        let n = digit.to_digit(10).ok_or(RuleParseError::InvalidDigit(digit))?;
+        if n > 8 {
+            return Err(RuleParseError::OutOfRange(n));
+        }
+        table[n as usize] = true;
+    }
+    Ok(())
+}
+
+impl Default for Rule {
+    /// Defaults to standard Conway `B3/S23`.
+    fn default() -> Self {
+        Self::parse("B3/S23").expect("\"B3/S23\" is always a valid rulestring")
+    }
+}
+
+#[derive(Debug)]
+pub enum RuleParseError {
+    MissingTag(String),
+    MissingHalf,
+    InvalidDigit(char),
+    OutOfRange(u32),
+    InvalidStateCount(String),
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseError::MissingTag(half) => write!(f, "rule half `{half}` is missing a B/S tag"),
+            RuleParseError::MissingHalf => write!(f, "rulestring is missing a B or S half"),
+            RuleParseError::InvalidDigit(c) => write!(f, "`{c}` is not a valid neighbor count digit"),
+            RuleParseError::OutOfRange(n) => write!(f, "neighbor count {n} is out of range 0..=8"),
+            RuleParseError::InvalidStateCount(s) => write!(f, "`{s}` is not a valid Generations state count (>= 2)"),
+        }
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_conway() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        assert_eq!(rule.birth, [false, false, false, true, false, false, false, false, false]);
+        assert_eq!(rule.survive, [false, false, true, true, false, false, false, false, false]);
+        assert_eq!(rule.states, 2);
+        assert!(!rule.is_generations());
+    }
+
+    #[test]
+    fn parses_generations_state_count() {
+        let rule = Rule::parse("B2/S23/3").unwrap();
+        assert_eq!(rule.states, 3);
+        assert!(rule.is_generations());
+    }
+
+    #[test]
+    fn rejects_missing_half() {
+        assert!(matches!(Rule::parse("B3"), Err(RuleParseError::MissingHalf)));
+    }
+
+    #[test]
+    fn rejects_out_of_range_neighbor_count() {
+        assert!(matches!(Rule::parse("B9/S23"), Err(RuleParseError::OutOfRange(9))));
+    }
+}